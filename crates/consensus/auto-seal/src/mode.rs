@@ -153,3 +153,62 @@ impl fmt::Debug for ReadyTransactionMiner {
             .finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::future::poll_fn;
+    use reth_transaction_pool::test_utils::{testing_pool, MockTransaction};
+    use tokio::sync::mpsc::channel;
+
+    #[tokio::test]
+    async fn instant_mining_seals_at_most_max_transactions() {
+        let pool = testing_pool();
+        let (tx, rx) = channel(16);
+        let mut mode = MiningMode::instant(2, rx);
+
+        for _ in 0..5 {
+            let transaction = MockTransaction::eip1559();
+            let hash = pool.add_external_transaction(transaction).await.unwrap();
+            tx.send(hash).await.unwrap();
+        }
+
+        // only `max_transactions` of the five ready transactions go into the block
+        let mined = poll_fn(|cx| mode.poll(&pool, cx)).await;
+        assert_eq!(mined.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn instant_mining_waits_for_transactions() {
+        let pool = testing_pool();
+        let (tx, rx) = channel(16);
+        let mut mode = MiningMode::instant(2, rx);
+
+        let polled = poll_fn(|cx| Poll::Ready(mode.poll(&pool, cx))).await;
+        assert!(polled.is_pending());
+
+        let hash = pool.add_external_transaction(MockTransaction::eip1559()).await.unwrap();
+        tx.send(hash).await.unwrap();
+
+        let mined = poll_fn(|cx| mode.poll(&pool, cx)).await;
+        assert_eq!(mined.len(), 1);
+        assert_eq!(*mined[0].hash(), hash);
+    }
+
+    #[tokio::test]
+    async fn interval_mining_seals_on_tick() {
+        let pool = testing_pool();
+        let mut mode = MiningMode::interval(Duration::from_millis(50));
+
+        for _ in 0..3 {
+            pool.add_external_transaction(MockTransaction::eip1559()).await.unwrap();
+        }
+
+        // nothing is sealed before the first tick
+        let polled = poll_fn(|cx| Poll::Ready(mode.poll(&pool, cx))).await;
+        assert!(polled.is_pending());
+
+        let mined = poll_fn(|cx| mode.poll(&pool, cx)).await;
+        assert_eq!(mined.len(), 3);
+    }
+}
@@ -478,7 +478,7 @@ mod tests {
     use reth_primitives::{
         constants::eip4844::DATA_GAS_PER_BLOB, hex_literal::hex, proofs, Account, Address,
         BlockBody, BlockHash, BlockHashOrNumber, Bytes, ChainSpecBuilder, Header, Signature,
-        TransactionKind, TransactionSigned, Withdrawal, Withdrawals, MAINNET, U256,
+        TransactionKind, TransactionSigned, Withdrawal, Withdrawals, B256, MAINNET, U256,
     };
     use std::ops::RangeBounds;
 
@@ -826,6 +826,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn header_gas_used_exceeds_gas_limit() {
+        let chain_spec = ChainSpecBuilder::mainnet().build();
+
+        let header = Header { gas_used: 1001, gas_limit: 1000, ..Default::default() }.seal_slow();
+
+        assert_eq!(
+            validate_header_standalone(&header, &chain_spec),
+            Err(ConsensusError::HeaderGasUsedExceedsGasLimit { gas_used: 1001, gas_limit: 1000 })
+        );
+    }
+
+    #[test]
+    fn cancun_header_blob_gas_used_exceeds_max() {
+        let chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
+
+        // one blob more than allowed per block
+        let blob_gas_used = MAX_DATA_GAS_PER_BLOCK + DATA_GAS_PER_BLOB;
+        let header = Header {
+            base_fee_per_gas: Some(1337u64),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&Withdrawals::default())),
+            blob_gas_used: Some(blob_gas_used),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            ..Default::default()
+        }
+        .seal_slow();
+
+        assert_eq!(
+            validate_header_standalone(&header, &chain_spec),
+            Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used,
+                max_blob_gas_per_block: MAX_DATA_GAS_PER_BLOCK,
+            })
+        );
+    }
+
     #[test]
     fn test_valid_gas_limit_increase() {
         let parent = SealedHeader {
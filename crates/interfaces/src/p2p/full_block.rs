@@ -734,8 +734,9 @@ enum RangeResponseResult {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::TestFullBlockClient;
+    use crate::test_utils::{TestFullBlockClient, TestHeadersOnlyClient};
     use futures::StreamExt;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn download_single_full_block() {
@@ -859,4 +860,26 @@ mod tests {
             assert_eq!(block.header.number, expected_number);
         }
     }
+
+    #[tokio::test]
+    async fn download_full_block_stalled_bodies() {
+        let client = TestHeadersOnlyClient::default();
+        let header = SealedHeader::default();
+        let body = BlockBody::default();
+        client.insert(header.clone(), body);
+        let full_block_client = FullBlockClient::test_client(client.clone());
+
+        // the header is still served
+        let received = client.get_header(header.hash().into()).await.unwrap();
+        assert_eq!(received.into_data(), Some(header.clone().unseal()));
+
+        // but the full block never resolves since the bodies request stalls
+        let received = tokio::time::timeout(
+            Duration::from_millis(100),
+            full_block_client.get_full_block(header.hash()),
+        )
+        .await;
+        assert!(received.is_err());
+        assert_eq!(client.bodies_requests(), 1);
+    }
 }
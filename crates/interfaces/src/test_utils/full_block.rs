@@ -10,7 +10,13 @@ use reth_primitives::{
     BlockBody, BlockHashOrNumber, BlockNumHash, Header, HeadersDirection, PeerId, SealedBlock,
     SealedHeader, WithPeerId, B256,
 };
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 /// A headers+bodies client implementation that does nothing.
 #[derive(Debug, Default, Clone)]
@@ -238,3 +244,64 @@ impl BodiesClient for TestFullBlockClient {
         )))
     }
 }
+
+/// A headers+bodies client that serves headers like [TestFullBlockClient], but never responds to
+/// bodies requests.
+///
+/// This simulates a protocol-valid peer that stalls on bodies. Downloaders don't time out requests
+/// themselves, so a download against this client only makes progress on headers, and callers
+/// must bound it themselves.
+#[derive(Clone, Debug, Default)]
+pub struct TestHeadersOnlyClient {
+    inner: TestFullBlockClient,
+    // number of bodies requests that were received and left unanswered
+    bodies_requests: Arc<AtomicUsize>,
+}
+
+impl TestHeadersOnlyClient {
+    /// Insert a header and body into the client maps.
+    ///
+    /// Only the header will ever be served.
+    pub fn insert(&self, header: SealedHeader, body: BlockBody) {
+        self.inner.insert(header, body)
+    }
+
+    /// Returns the number of bodies requests this client has stalled on.
+    pub fn bodies_requests(&self) -> usize {
+        self.bodies_requests.load(Ordering::Relaxed)
+    }
+}
+
+impl DownloadClient for TestHeadersOnlyClient {
+    fn report_bad_message(&self, _peer_id: PeerId) {}
+
+    fn num_connected_peers(&self) -> usize {
+        1
+    }
+}
+
+impl HeadersClient for TestHeadersOnlyClient {
+    type Output = futures::future::Ready<PeerRequestResult<Vec<Header>>>;
+
+    fn get_headers_with_priority(
+        &self,
+        request: HeadersRequest,
+        priority: Priority,
+    ) -> Self::Output {
+        self.inner.get_headers_with_priority(request, priority)
+    }
+}
+
+impl BodiesClient for TestHeadersOnlyClient {
+    /// Bodies requests are never resolved.
+    type Output = futures::future::Pending<PeerRequestResult<Vec<BlockBody>>>;
+
+    fn get_block_bodies_with_priority(
+        &self,
+        _hashes: Vec<B256>,
+        _priority: Priority,
+    ) -> Self::Output {
+        self.bodies_requests.fetch_add(1, Ordering::Relaxed);
+        futures::future::pending()
+    }
+}
@@ -304,4 +304,45 @@ mod tests {
             (headers.into_iter().filter(|h| !h.is_empty()).count() as u64 + 1) / 2
         );
     }
+
+    /// Check that the request future retries on undersized and empty responses and reports the
+    /// peer for every empty response.
+    #[tokio::test]
+    async fn request_retries_on_partial_and_empty_responses() {
+        let empty_mod = 3;
+
+        // batch sizes that don't evenly divide the number of bodies, so the client responds with
+        // fewer bodies than requested until the last batch, which is smaller than the batch size
+        for batch_size in [2, 3, 7] {
+            // Generate some random blocks, roughly half of which are non-empty
+            let (headers, mut bodies) = generate_bodies(0..=99);
+            let non_empty = headers.iter().filter(|h| !h.is_empty()).count();
+            assert!(non_empty > batch_size, "batch size {batch_size} would not be undersized");
+
+            let client = Arc::new(
+                TestBodiesClient::default()
+                    .with_bodies(bodies.clone())
+                    .with_max_batch_size(batch_size)
+                    .with_empty_responses(empty_mod),
+            );
+            let fut = BodiesRequestFuture::new(
+                client.clone(),
+                Arc::new(TestConsensus::default()),
+                BodyDownloaderMetrics::default(),
+            )
+            .with_headers(headers.clone());
+
+            assert_eq!(fut.await.unwrap(), zip_blocks(headers.iter(), &mut bodies));
+
+            // every `empty_mod`th response was empty and had to be retried
+            let times_requested = client.times_requested();
+            let empty_responses = times_requested / empty_mod;
+            assert_eq!(
+                times_requested - empty_responses,
+                non_empty.div_ceil(batch_size) as u64,
+                "batch size {batch_size}"
+            );
+            assert_eq!(client.bad_message_reports(), empty_responses, "batch size {batch_size}");
+        }
+    }
 }
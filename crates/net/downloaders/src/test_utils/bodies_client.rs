@@ -23,6 +23,7 @@ pub struct TestBodiesClient {
     max_batch_size: Option<usize>,
     times_requested: AtomicU64,
     empty_response_mod: Option<u64>,
+    bad_message_reports: AtomicU64,
 }
 
 impl TestBodiesClient {
@@ -52,6 +53,11 @@ impl TestBodiesClient {
         self.times_requested.load(Ordering::Relaxed)
     }
 
+    /// Returns how many times a peer was reported for a bad message.
+    pub(crate) fn bad_message_reports(&self) -> u64 {
+        self.bad_message_reports.load(Ordering::Relaxed)
+    }
+
     /// Returns whether or not the client should respond with an empty response.
     ///
     /// This will only return true if `empty_response_mod` is `Some`, and `times_requested %
@@ -67,7 +73,7 @@ impl TestBodiesClient {
 
 impl DownloadClient for TestBodiesClient {
     fn report_bad_message(&self, _peer_id: PeerId) {
-        // noop
+        self.bad_message_reports.fetch_add(1, Ordering::Relaxed);
     }
 
     fn num_connected_peers(&self) -> usize {
@@ -933,11 +933,18 @@ impl Peer {
         Self { kind, ..Self::new(addr) }
     }
 
-    /// Resets the reputation of the peer to the default value. This always returns
+    /// Resets the reputation of the peer to the default value.
+    ///
+    /// Returns [`ReputationChangeOutcome::Unban`] if the peer was banned, otherwise
     /// [`ReputationChangeOutcome::None`].
     fn reset_reputation(&mut self) -> ReputationChangeOutcome {
+        let was_banned = self.is_banned();
         self.reputation = DEFAULT_REPUTATION;
 
+        if was_banned {
+            return ReputationChangeOutcome::Unban
+        }
+
         ReputationChangeOutcome::None
     }
 
@@ -1924,6 +1931,57 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_reset_reputation_unbans() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let mut peers = PeersManager::default();
+        peers.add_peer(peer, socket_addr, None);
+
+        match event!(peers) {
+            PeerAction::PeerAdded(peer_id) => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+        match event!(peers) {
+            PeerAction::Connect { peer_id, .. } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+
+        peers.apply_reputation_change(&peer, ReputationChangeKind::BadProtocol);
+        peers.on_active_session_gracefully_closed(peer);
+        assert!(peers.ban_list.is_banned_peer(&peer));
+
+        match event!(peers) {
+            PeerAction::Disconnect { peer_id, .. } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+        match event!(peers) {
+            PeerAction::BanPeer { peer_id } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Reset);
+
+        let p = peers.peers.get(&peer).unwrap();
+        assert_eq!(p.reputation, DEFAULT_REPUTATION);
+        assert!(!peers.ban_list.is_banned_peer(&peer));
+
+        match event!(peers) {
+            PeerAction::UnBanPeer { peer_id } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[tokio::test]
     async fn test_reputation_management() {
         let peer = PeerId::random();
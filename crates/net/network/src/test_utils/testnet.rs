@@ -10,10 +10,11 @@ use crate::{
     NetworkManager,
 };
 use futures::{FutureExt, StreamExt};
+use parking_lot::Mutex;
 use pin_project::pin_project;
 use reth_eth_wire::{protocol::Protocol, DisconnectReason, HelloMessageWithProtocols};
-use reth_network_api::{NetworkInfo, Peers};
-use reth_primitives::{PeerId, MAINNET};
+use reth_network_api::{NetworkInfo, Peers, ReputationChangeKind};
+use reth_primitives::{NodeRecord, PeerId, MAINNET};
 use reth_provider::{
     test_utils::NoopProvider, BlockReader, BlockReaderIdExt, HeaderProvider, StateProviderFactory,
 };
@@ -30,6 +31,7 @@ use std::{
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 use tokio::{
     sync::{
@@ -213,7 +215,7 @@ where
             }
         });
 
-        TestnetHandle { _handle: handle, peers, terminate: tx }
+        TestnetHandle { _handle: handle, peers, terminate: tx, partition: Default::default() }
     }
 }
 
@@ -271,6 +273,8 @@ pub struct TestnetHandle<C, Pool> {
     _handle: JoinHandle<()>,
     peers: Vec<PeerHandle<Pool>>,
     terminate: oneshot::Sender<oneshot::Sender<Testnet<C, Pool>>>,
+    /// The groups of peer indices of the active partition, if any.
+    partition: Mutex<Vec<Vec<usize>>>,
 }
 
 // === impl TestnetHandle ===
@@ -312,6 +316,60 @@ impl<C, Pool> TestnetHandle<C, Pool> {
             .map(|(mut stream, num)| async move { stream.take_session_established(num).await });
         futures::future::join_all(fut).await;
     }
+
+    /// Partitions the network into the given groups of peer indices.
+    ///
+    /// Peers in different groups ban each other, which closes their sessions and prevents them
+    /// from reconnecting until the partition is healed with [`Self::heal`].
+    ///
+    /// # Panics
+    ///
+    /// If the network is already partitioned.
+    pub fn partition(&self, groups: &[&[usize]]) {
+        let mut partition = self.partition.lock();
+        assert!(partition.is_empty(), "network is already partitioned");
+
+        *partition = groups.iter().map(|group| group.to_vec()).collect();
+        for (a, b) in self.cross_group_peers(&partition) {
+            a.ban(*b.peer_id());
+            b.ban(*a.peer_id());
+        }
+    }
+
+    /// Heals the partition previously created with [`Self::partition`], if any.
+    ///
+    /// This unbans peers in different groups and reconnects them, waiting for all sessions to be
+    /// established.
+    pub async fn heal(&self) {
+        let groups = std::mem::take(&mut *self.partition.lock());
+
+        let mut established = Vec::new();
+        for (a, b) in self.cross_group_peers(&groups) {
+            // listen before unbanning, since `b` may redial `a` as soon as its ban is lifted
+            let mut stream = NetworkEventStream::new(a.event_listener());
+            a.unban(*b.peer_id());
+            b.unban(*a.peer_id());
+
+            let peer_id = *b.peer_id();
+            a.connect_enode(&b.enode());
+            established.push(async move { stream.expect_session_established(peer_id).await });
+        }
+        futures::future::join_all(established).await;
+    }
+
+    /// Returns all pairs of peers that are in different groups.
+    fn cross_group_peers<'a>(
+        &'a self,
+        groups: &'a [Vec<usize>],
+    ) -> impl Iterator<Item = (&'a PeerHandle<Pool>, &'a PeerHandle<Pool>)> + 'a {
+        groups.iter().enumerate().flat_map(move |(idx, group)| {
+            groups[idx + 1..].iter().flat_map(move |other| {
+                group.iter().flat_map(move |a| {
+                    other.iter().map(move |b| (&self.peers[*a], &self.peers[*b]))
+                })
+            })
+        })
+    }
 }
 
 /// A peer in the [`Testnet`].
@@ -365,6 +423,16 @@ where
         *self.network.peer_id()
     }
 
+    /// Returns the [`NodeRecord`] of this peer that other nodes can use to connect to it.
+    pub fn enode(&self) -> NodeRecord {
+        local_enode(self.local_addr(), self.peer_id())
+    }
+
+    /// Adds the node identified by the given [`NodeRecord`] as a peer.
+    pub fn connect_enode(&self, enode: &NodeRecord) {
+        self.network.handle().add_peer(enode.id, enode.tcp_addr());
+    }
+
     /// Returns mutable access to the network.
     pub fn network_mut(&mut self) -> &mut NetworkManager<C> {
         &mut self.network
@@ -479,11 +547,42 @@ impl<Pool> PeerHandle<Pool> {
         self.network.local_addr()
     }
 
+    /// Returns the [`NodeRecord`] of this peer that other nodes can use to connect to it.
+    pub fn enode(&self) -> NodeRecord {
+        local_enode(self.local_addr(), *self.peer_id())
+    }
+
+    /// Adds the node identified by the given [`NodeRecord`] as a peer.
+    pub fn connect_enode(&self, enode: &NodeRecord) {
+        self.network.add_peer(enode.id, enode.tcp_addr());
+    }
+
     /// Creates a new [`NetworkEvent`] listener channel.
     pub fn event_listener(&self) -> UnboundedReceiverStream<NetworkEvent> {
         self.network.event_listener()
     }
 
+    /// Disconnects the session with the given peer, if any.
+    pub fn disconnect(&self, peer_id: PeerId) {
+        self.network.disconnect_peer(peer_id);
+    }
+
+    /// Bans the given peer and disconnects its active session, if any.
+    ///
+    /// This applies the [`ReputationChangeKind::BadProtocol`] reputation change, which drops the
+    /// peer's reputation to the minimum.
+    pub fn ban(&self, peer_id: PeerId) {
+        self.network.reputation_change(peer_id, ReputationChangeKind::BadProtocol);
+    }
+
+    /// Lifts a ban previously placed with [`Self::ban`].
+    ///
+    /// This applies the [`ReputationChangeKind::Reset`] reputation change, which restores the
+    /// default reputation and removes the peer from the ban list.
+    pub fn unban(&self, peer_id: PeerId) {
+        self.network.reputation_change(peer_id, ReputationChangeKind::Reset);
+    }
+
     /// Returns the [`TransactionsHandle`] of this peer.
     pub fn transactions(&self) -> Option<&TransactionsHandle> {
         self.transactions.as_ref()
@@ -563,6 +662,20 @@ impl Default for PeerConfig {
     }
 }
 
+/// How long [`NetworkEventStream`] helpers wait for an expected session event.
+const SESSION_EVENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Returns the [`NodeRecord`] for a testnet peer bound to `addr`.
+///
+/// Testnet peers listen on the unspecified address, which is not dialable, so it's replaced with
+/// loopback.
+fn local_enode(mut addr: SocketAddr, id: PeerId) -> NodeRecord {
+    if addr.ip().is_unspecified() {
+        addr.set_ip(Ipv4Addr::LOCALHOST.into());
+    }
+    NodeRecord::new(addr, id)
+}
+
 /// A helper type to await network events
 ///
 /// This makes it easier to await established connections
@@ -590,6 +703,46 @@ impl NetworkEventStream {
         None
     }
 
+    /// Awaits the [`NetworkEvent::SessionClosed`] event for the given peer, skipping all other
+    /// events.
+    ///
+    /// # Panics
+    ///
+    /// If the stream ends or the session isn't closed within [`SESSION_EVENT_TIMEOUT`].
+    pub async fn expect_session_closed(&mut self, peer_id: PeerId) -> Option<DisconnectReason> {
+        let closed = async {
+            while let Some((closed, reason)) = self.next_session_closed().await {
+                if closed == peer_id {
+                    return reason
+                }
+            }
+            panic!("event stream ended before session with {peer_id} was closed")
+        };
+        tokio::time::timeout(SESSION_EVENT_TIMEOUT, closed).await.unwrap_or_else(|_| {
+            panic!("session with {peer_id} not closed within {SESSION_EVENT_TIMEOUT:?}")
+        })
+    }
+
+    /// Awaits the [`NetworkEvent::SessionEstablished`] event for the given peer, skipping all
+    /// other events.
+    ///
+    /// # Panics
+    ///
+    /// If the stream ends or the session isn't established within [`SESSION_EVENT_TIMEOUT`].
+    pub async fn expect_session_established(&mut self, peer_id: PeerId) {
+        let established = async {
+            while let Some(established) = self.next_session_established().await {
+                if established == peer_id {
+                    return
+                }
+            }
+            panic!("event stream ended before session with {peer_id} was established")
+        };
+        tokio::time::timeout(SESSION_EVENT_TIMEOUT, established).await.unwrap_or_else(|_| {
+            panic!("session with {peer_id} not established within {SESSION_EVENT_TIMEOUT:?}")
+        })
+    }
+
     /// Awaits the next event for an established session
     pub async fn next_session_established(&mut self) -> Option<PeerId> {
         while let Some(ev) = self.inner.next().await {
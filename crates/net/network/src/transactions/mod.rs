@@ -372,6 +372,8 @@ where
             // filter all transactions unknown to the peer
             let mut hashes = PooledTransactionsHashesBuilder::new(peer.version);
             let mut full_transactions = FullTransactionsBuilder::default();
+            // hashes of 4844 transactions, which are announced even if full transactions are sent
+            let mut blob_hashes = PooledTransactionsHashesBuilder::new(peer.version);
 
             // Iterate through the transactions to propagate and fill the hashes and full
             // transaction lists, before deciding whether or not to send full transactions to the
@@ -388,7 +390,9 @@ where
                     //  via `GetPooledTransactions`.
                     //
                     // From: <https://eips.ethereum.org/EIPS/eip-4844#networking>
-                    if !tx.transaction.is_eip4844() {
+                    if tx.transaction.is_eip4844() {
+                        blob_hashes.push(tx);
+                    } else {
                         full_transactions.push(tx);
                     }
                 }
@@ -428,6 +432,27 @@ where
 
                     // send full transactions
                     self.network.send_transactions(*peer_id, new_full_transactions);
+
+                    // blob transactions are never sent in full, so they still need to be announced
+                    let mut blob_hashes = blob_hashes.build();
+                    if !blob_hashes.is_empty() {
+                        // enforce tx soft limit per message, same as for regular announcements
+                        blob_hashes.truncate(
+                            SOFT_LIMIT_COUNT_HASHES_IN_NEW_POOLED_TRANSACTIONS_MEMPOOL_PACKET,
+                        );
+
+                        for hash in blob_hashes.iter_hashes().copied() {
+                            propagated
+                                .0
+                                .entry(hash)
+                                .or_default()
+                                .push(PropagateKind::Hash(*peer_id));
+                        }
+
+                        trace!(target: "net::tx", ?peer_id, num_txs=?blob_hashes.len(), "Propagating blob tx hashes to peer");
+
+                        self.network.send_transactions_hashes(*peer_id, blob_hashes);
+                    }
                 }
             }
         }
@@ -1885,6 +1910,135 @@ mod tests {
         assert!(tx_fetcher.buffered_hashes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_blob_transactions_only_propagated_as_hashes() {
+        reth_tracing::init_test_tracing();
+
+        let mut tx_manager = new_tx_manager().await;
+
+        let peer_id = PeerId::new([1; 64]);
+        let (peer, _to_mock_session_rx) = new_mock_session(peer_id, EthVersion::Eth68);
+        tx_manager.peers.insert(peer_id, peer);
+
+        let blob_tx = MockTransaction::eip4844();
+        let tx = MockTransaction::eip1559();
+        for tx in [blob_tx.clone(), tx.clone()] {
+            tx_manager
+                .pool
+                .add_transaction(reth_transaction_pool::TransactionOrigin::External, tx)
+                .await
+                .unwrap();
+        }
+
+        // a blob transaction on its own is announced by hash
+        let to_propagate = tx_manager
+            .pool
+            .get_all(vec![blob_tx.get_hash()])
+            .into_iter()
+            .map(PropagateTransaction::new)
+            .collect();
+        let propagated = tx_manager.propagate_transactions(to_propagate);
+        assert_eq!(
+            propagated.0.get(&blob_tx.get_hash()),
+            Some(&vec![PropagateKind::Hash(peer_id)])
+        );
+
+        // a blob transaction is still announced by hash when sent alongside full transactions
+        let (peer, _to_mock_session_rx) = new_mock_session(peer_id, EthVersion::Eth68);
+        tx_manager.peers.insert(peer_id, peer);
+        let to_propagate = tx_manager
+            .pool
+            .get_all(vec![blob_tx.get_hash(), tx.get_hash()])
+            .into_iter()
+            .map(PropagateTransaction::new)
+            .collect();
+        let propagated = tx_manager.propagate_transactions(to_propagate);
+        assert_eq!(propagated.0.get(&tx.get_hash()), Some(&vec![PropagateKind::Full(peer_id)]));
+        assert_eq!(
+            propagated.0.get(&blob_tx.get_hash()),
+            Some(&vec![PropagateKind::Hash(peer_id)])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blob_transactions_announced_to_full_and_hash_peers() {
+        reth_tracing::init_test_tracing();
+
+        let mut tx_manager = new_tx_manager().await;
+
+        // with 5 peers, full transactions are sent to 4 of them and hashes to the remaining one
+        let mut sessions = Vec::new();
+        for idx in 1..=5 {
+            let peer_id = PeerId::new([idx; 64]);
+            let (peer, to_mock_session_rx) = new_mock_session(peer_id, EthVersion::Eth68);
+            tx_manager.peers.insert(peer_id, peer);
+            sessions.push(to_mock_session_rx);
+        }
+
+        let blob_tx = MockTransaction::eip4844();
+        let tx = MockTransaction::eip1559();
+        for tx in [blob_tx.clone(), tx.clone()] {
+            tx_manager
+                .pool
+                .add_transaction(reth_transaction_pool::TransactionOrigin::External, tx)
+                .await
+                .unwrap();
+        }
+
+        let to_propagate = tx_manager
+            .pool
+            .get_all(vec![blob_tx.get_hash(), tx.get_hash()])
+            .into_iter()
+            .map(PropagateTransaction::new)
+            .collect();
+        let propagated = tx_manager.propagate_transactions(to_propagate);
+
+        let tx_kinds = propagated.0.get(&tx.get_hash()).unwrap();
+        let (full, hash): (Vec<_>, Vec<_>) =
+            tx_kinds.iter().partition(|kind| matches!(kind, PropagateKind::Full(_)));
+        assert_eq!(full.len(), 4);
+        assert_eq!(hash.len(), 1);
+
+        // the blob transaction is announced by hash to every peer, regardless of whether the
+        // other transactions were sent in full
+        let blob_kinds = propagated.0.get(&blob_tx.get_hash()).unwrap();
+        assert_eq!(blob_kinds.len(), 5);
+        assert!(blob_kinds.iter().all(|kind| matches!(kind, PropagateKind::Hash(_))));
+        for kind in tx_kinds {
+            assert!(blob_kinds.contains(&PropagateKind::Hash(*kind.peer())));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_announced_blob_transaction_fetched_via_get_pooled_transactions() {
+        reth_tracing::init_test_tracing();
+
+        let mut tx_manager = new_tx_manager().await;
+
+        let peer_id = PeerId::new([1; 64]);
+        let (peer, mut to_mock_session_rx) = new_mock_session(peer_id, EthVersion::Eth68);
+        tx_manager.peers.insert(peer_id, peer);
+
+        // peer announces a blob transaction we don't know yet
+        let blob_tx = MockTransaction::eip4844();
+        let msg = NewPooledTransactionHashes::Eth68(NewPooledTransactionHashes68 {
+            hashes: vec![blob_tx.get_hash()],
+            // a blob tx with a single blob
+            sizes: vec![131_072],
+            types: vec![blob_tx.tx_type()],
+        });
+        tx_manager.on_new_pooled_transaction_hashes(peer_id, msg);
+
+        // the blob transaction is requested from the announcing peer
+        let req = to_mock_session_rx
+            .recv()
+            .await
+            .expect("peer session should receive request for announced blob tx");
+        let PeerRequest::GetPooledTransactions { request, .. } = req else { unreachable!() };
+        let GetPooledTransactions(hashes) = request;
+        assert_eq!(hashes, vec![blob_tx.get_hash()]);
+    }
+
     /*#[tokio::test]
     async fn fill_eth68_request_for_peer() {
         reth_tracing::init_test_tracing();
@@ -193,6 +193,110 @@ async fn test_get_peer_by_id() {
     assert!(peer.is_none());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_connect_by_enode() {
+    reth_tracing::init_test_tracing();
+
+    let net = Testnet::create(2).await;
+    let handle = net.spawn();
+
+    let peer0 = &handle.peers()[0];
+    let peer1 = &handle.peers()[1];
+
+    // roundtrip through the string form an external node would hand out
+    let enode: NodeRecord = peer1.enode().to_string().parse().unwrap();
+    assert_eq!(enode.id, *peer1.peer_id());
+    assert!(!enode.address.is_unspecified());
+    assert!(enode.address.is_loopback());
+    assert_eq!(enode.tcp_port, peer1.local_addr().port());
+
+    let mut listener0 = NetworkEventStream::new(peer0.event_listener());
+    peer0.connect_enode(&enode);
+
+    let peer_id = listener0.next_session_established().await.unwrap();
+    assert_eq!(peer_id, *peer1.peer_id());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_disconnect_and_ban_peer() {
+    reth_tracing::init_test_tracing();
+
+    let net = Testnet::create(2).await;
+    let handle = net.spawn();
+
+    let peer0 = &handle.peers()[0];
+    let peer1 = &handle.peers()[1];
+    let peer1_id = *peer1.peer_id();
+
+    let mut listener0 = NetworkEventStream::new(peer0.event_listener());
+    peer0.connect_enode(&peer1.enode());
+    assert_eq!(listener0.next_session_established().await.unwrap(), peer1_id);
+
+    // a disconnected peer can reconnect
+    peer0.disconnect(peer1_id);
+    listener0.expect_session_closed(peer1_id).await;
+
+    peer0.connect_enode(&peer1.enode());
+    assert_eq!(listener0.next_session_established().await.unwrap(), peer1_id);
+
+    // banning closes the session
+    peer0.ban(peer1_id);
+    listener0.expect_session_closed(peer1_id).await;
+
+    let reputation = peer0.network().reputation_by_id(peer1_id).await.unwrap();
+    assert_eq!(reputation, Some(i32::MIN));
+
+    // unbanning restores the reputation and allows the peer to reconnect
+    peer0.unban(peer1_id);
+    let reputation = peer0.network().reputation_by_id(peer1_id).await.unwrap();
+    assert_eq!(reputation, Some(0));
+
+    peer0.connect_enode(&peer1.enode());
+    assert_eq!(listener0.next_session_established().await.unwrap(), peer1_id);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partition_and_heal() {
+    reth_tracing::init_test_tracing();
+
+    let net = Testnet::create(3).await;
+    let handle = net.spawn();
+    handle.connect_peers().await;
+
+    let peer0 = &handle.peers()[0];
+    let mut listener0 = NetworkEventStream::new(peer0.event_listener());
+
+    // split peer0 from the other two
+    handle.partition(&[&[0], &[1, 2]]);
+
+    let peer1_id = *handle.peers()[1].peer_id();
+    let peer2_id = *handle.peers()[2].peer_id();
+    // the sessions may close in either order
+    let closed = tokio::time::timeout(Duration::from_secs(10), async {
+        let mut closed = HashSet::new();
+        while closed.len() < 2 {
+            let (peer_id, _) = listener0.next_session_closed().await.unwrap();
+            closed.insert(peer_id);
+        }
+        closed
+    })
+    .await
+    .unwrap();
+    assert_eq!(closed, HashSet::from([peer1_id, peer2_id]));
+
+    handle.heal().await;
+
+    // the healed sessions must not be torn down again by a lingering ban
+    let closed =
+        tokio::time::timeout(Duration::from_secs(2), listener0.next_session_closed()).await;
+    assert!(closed.is_err(), "session closed after heal: {closed:?}");
+    assert_eq!(peer0.network().num_connected_peers(), 2);
+    for peer_id in [peer1_id, peer2_id] {
+        let reputation = peer0.network().reputation_by_id(peer_id).await.unwrap();
+        assert_eq!(reputation, Some(0));
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[ignore]
 async fn test_connect_with_boot_nodes() {
@@ -4,7 +4,9 @@ use rand::thread_rng;
 use reth_network::test_utils::Testnet;
 use reth_primitives::U256;
 use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
-use reth_transaction_pool::{test_utils::TransactionGenerator, PoolTransaction, TransactionPool};
+use reth_transaction_pool::{
+    test_utils::TransactionGenerator, PoolTransaction, TransactionOrigin, TransactionPool,
+};
 #[tokio::test(flavor = "multi_thread")]
 async fn test_tx_gossip() {
     reth_tracing::init_test_tracing();
@@ -42,3 +44,43 @@ async fn test_tx_gossip() {
     let received = peer1_tx_listener.recv().await.unwrap();
     assert_eq!(received, hash);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_private_tx_not_gossiped() {
+    reth_tracing::init_test_tracing();
+
+    let provider = MockEthProvider::default();
+    let net = Testnet::create_with(2, provider.clone()).await;
+
+    // install request handlers
+    let net = net.with_eth_pool();
+    let handle = net.spawn();
+    // connect all the peers
+    handle.connect_peers().await;
+
+    let peer0 = &handle.peers()[0];
+    let peer1 = &handle.peers()[1];
+
+    let peer0_pool = peer0.pool().unwrap();
+    let mut peer1_tx_listener = peer1.pool().unwrap().pending_transactions_listener();
+
+    let mut gen = TransactionGenerator::new(thread_rng());
+    let private_tx = gen.gen_eip1559_pooled();
+    let public_tx = gen.gen_eip1559_pooled();
+
+    // ensure the senders have balance
+    for tx in [&private_tx, &public_tx] {
+        provider.add_account(tx.sender(), ExtendedAccount::new(0, U256::from(100_000_000)));
+    }
+
+    // the private tx is inserted first, so it would be gossiped first if it were propagated
+    let private_hash =
+        peer0_pool.add_transaction(TransactionOrigin::Private, private_tx).await.unwrap();
+    let public_hash = peer0_pool.add_external_transaction(public_tx).await.unwrap();
+
+    // ensure only the public tx is gossiped to peer1
+    let received = peer1_tx_listener.recv().await.unwrap();
+    assert_eq!(received, public_hash);
+    assert!(!peer1.pool().unwrap().contains(&private_hash));
+    assert!(peer0_pool.contains(&private_hash));
+}
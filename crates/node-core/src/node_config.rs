@@ -41,7 +41,7 @@ use reth_primitives::{
     constants::eip4844::{LoadKzgSettingsError, MAINNET_KZG_TRUSTED_SETUP},
     kzg::KzgSettings,
     stage::StageId,
-    BlockHashOrNumber, BlockNumber, ChainSpec, Head, SealedHeader, TxHash, B256, MAINNET,
+    BlockHashOrNumber, BlockNumber, ChainSpec, Head, SealedHeader, TxHash, B256, DEV, MAINNET,
 };
 use reth_provider::{
     providers::BlockchainProvider, BlockHashReader, BlockReader,
@@ -228,6 +228,31 @@ impl NodeConfig {
         test
     }
 
+    /// Creates a testing [NodeConfig] for the given [NodePreset].
+    ///
+    /// See also [NodeConfig::test].
+    pub fn test_with_preset(preset: NodePreset) -> Self {
+        let test = Self::test();
+        match preset {
+            NodePreset::Archive => test,
+            NodePreset::PrunedFull => test.with_pruning(PruningArgs { full: true }),
+            NodePreset::Sequencer => {
+                let mut test = test
+                    .with_chain(DEV.clone())
+                    .with_dev(DevArgs { dev: true, ..Default::default() });
+                test.rpc = test.rpc.with_http();
+                test.network.discovery.disable_discovery = true;
+                test
+            }
+            NodePreset::Follower => {
+                // without a consensus layer driving the engine API, continuous pipeline sync is the
+                // only way for a node to keep syncing the chain its peers build
+                test.with_chain(DEV.clone())
+                    .with_debug(DebugArgs { continuous: true, ..Default::default() })
+            }
+        }
+    }
+
     /// Set the datadir for the node
     pub fn with_datadir(mut self, datadir: MaybePlatformPath<DataDirPath>) -> Self {
         self.database = DatabaseBuilder::Real(datadir);
@@ -885,6 +910,23 @@ impl NodeConfig {
     }
 }
 
+/// Named [NodeConfig] presets for testing, see [NodeConfig::test_with_preset].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodePreset {
+    /// A node that keeps the entire history.
+    Archive,
+    /// A full node that prunes history, see [PruningArgs::full].
+    PrunedFull,
+    /// A node that builds and seals its own blocks on the dev chain, like `--dev`.
+    Sequencer,
+    /// A node on the dev chain that follows the blocks of its peers, e.g. of a
+    /// [NodePreset::Sequencer].
+    ///
+    /// Test nodes have no consensus layer, so this runs the pipeline in continuous mode, see
+    /// [DebugArgs::continuous].
+    Follower,
+}
+
 impl Default for NodeConfig {
     fn default() -> Self {
         Self {
@@ -907,3 +949,41 @@ impl Default for NodeConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archive_preset() {
+        let config = NodeConfig::test_with_preset(NodePreset::Archive);
+        assert!(!config.pruning.full);
+        assert!(!config.dev.dev);
+        assert!(!config.debug.continuous);
+    }
+
+    #[test]
+    fn pruned_full_preset() {
+        let config = NodeConfig::test_with_preset(NodePreset::PrunedFull);
+        assert!(config.pruning.full);
+        assert!(!config.dev.dev);
+    }
+
+    #[test]
+    fn sequencer_preset() {
+        let config = NodeConfig::test_with_preset(NodePreset::Sequencer);
+        assert!(config.dev.dev);
+        assert_eq!(config.chain.chain, DEV.chain);
+        assert!(config.rpc.http);
+        assert!(config.network.discovery.disable_discovery);
+        assert!(!config.debug.continuous);
+    }
+
+    #[test]
+    fn follower_preset() {
+        let config = NodeConfig::test_with_preset(NodePreset::Follower);
+        assert!(config.debug.continuous);
+        assert_eq!(config.chain.chain, DEV.chain);
+        assert!(!config.dev.dev);
+    }
+}
@@ -121,3 +121,71 @@ impl ExecutionPayloadValidator {
         Ok(sealed_block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{
+        constants::{eip4844::DATA_GAS_PER_BLOB, MIN_PROTOCOL_BASE_FEE},
+        proofs, sign_message,
+        test_chain_specs::post_merge_presets,
+        Block, Hardfork, Header, Transaction, TransactionKind, TransactionSigned, TxEip4844,
+        Withdrawals, B256,
+    };
+    use reth_rpc_types::engine::CancunPayloadFields;
+    use reth_rpc_types_compat::engine::payload::block_to_payload_v3;
+
+    /// Returns a block that contains a single blob transaction.
+    fn block_with_blob_transaction() -> SealedBlock {
+        let transaction = Transaction::Eip4844(TxEip4844 {
+            chain_id: 1,
+            gas_limit: 21_000,
+            max_fee_per_gas: MIN_PROTOCOL_BASE_FEE as u128,
+            to: TransactionKind::Call(Default::default()),
+            blob_versioned_hashes: vec![B256::with_last_byte(1)],
+            max_fee_per_blob_gas: 1,
+            ..Default::default()
+        });
+        let signature =
+            sign_message(B256::with_last_byte(1), transaction.signature_hash()).unwrap();
+        let body = vec![TransactionSigned::from_transaction_and_signature(transaction, signature)];
+
+        let header = Header {
+            transactions_root: proofs::calculate_transaction_root(&body),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+            base_fee_per_gas: Some(MIN_PROTOCOL_BASE_FEE),
+            blob_gas_used: Some(DATA_GAS_PER_BLOB),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            ..Default::default()
+        };
+        Block { header, body, ommers: Vec::new(), withdrawals: Some(Withdrawals::default()) }
+            .seal_slow()
+    }
+
+    #[test]
+    fn rejects_blob_transactions_before_cancun() {
+        let block = block_with_blob_transaction();
+        let cancun_fields = CancunPayloadFields {
+            parent_beacon_block_root: B256::ZERO,
+            versioned_hashes: block.blob_versioned_hashes_iter().copied().collect(),
+        };
+
+        for (fork, chain_spec) in post_merge_presets() {
+            let validator = ExecutionPayloadValidator::new(Arc::new(chain_spec));
+            let res = validator.ensure_well_formed_payload(
+                ExecutionPayload::V3(block_to_payload_v3(block.clone())),
+                Some(cancun_fields.clone()).into(),
+            );
+
+            if fork == Hardfork::Cancun {
+                assert_eq!(res.unwrap(), block, "{fork}");
+            } else {
+                assert!(
+                    matches!(res, Err(PayloadError::PreCancunBlockWithBlobTransactions)),
+                    "{fork}: {res:?}"
+                );
+            }
+        }
+    }
+}
@@ -13,6 +13,10 @@ mod spec;
 // The chain info module.
 mod info;
 
+// The chain spec presets for tests.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_chain_specs;
+
 #[cfg(test)]
 mod tests {
     use super::*;
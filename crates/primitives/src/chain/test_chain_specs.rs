@@ -0,0 +1,125 @@
+//! Common [ChainSpec] presets for tests.
+//!
+//! All presets use the mainnet chain id and genesis, so that tests don't have to repeat the same
+//! [ChainSpecBuilder] setup for every fork combination they want to cover.
+
+use crate::{ChainSpec, ChainSpecBuilder, ForkCondition, Hardfork, MAINNET};
+use std::collections::BTreeMap;
+
+/// Returns a [ChainSpec] with all supported hardforks activated at genesis.
+pub fn all_forks_active() -> ChainSpec {
+    up_to(Hardfork::Cancun)
+}
+
+/// Returns a [ChainSpec] with all hardforks up to and including `fork` activated at genesis.
+///
+/// Forks that only change the DAO balance or delay the difficulty bomb ([Hardfork::Dao],
+/// [Hardfork::MuirGlacier], [Hardfork::ArrowGlacier] and [Hardfork::GrayGlacier]) have no
+/// activation helper on [ChainSpecBuilder], so they map to the preset of the preceding fork.
+///
+/// # Panics
+///
+/// If `fork` is not an Ethereum mainnet hardfork.
+pub fn up_to(fork: Hardfork) -> ChainSpec {
+    let builder = builder();
+    let builder = match fork {
+        Hardfork::Frontier => builder.frontier_activated(),
+        Hardfork::Homestead | Hardfork::Dao => builder.homestead_activated(),
+        Hardfork::Tangerine => builder.tangerine_whistle_activated(),
+        Hardfork::SpuriousDragon => builder.spurious_dragon_activated(),
+        Hardfork::Byzantium => builder.byzantium_activated(),
+        Hardfork::Constantinople => builder.constantinople_activated(),
+        Hardfork::Petersburg => builder.petersburg_activated(),
+        Hardfork::Istanbul | Hardfork::MuirGlacier => builder.istanbul_activated(),
+        Hardfork::Berlin => builder.berlin_activated(),
+        Hardfork::London | Hardfork::ArrowGlacier | Hardfork::GrayGlacier => {
+            builder.london_activated()
+        }
+        Hardfork::Paris => builder.paris_activated(),
+        Hardfork::Shanghai => builder.shanghai_activated(),
+        Hardfork::Cancun => builder.cancun_activated(),
+        _ => panic!("no chain spec preset for {fork}"),
+    };
+    builder.build()
+}
+
+/// The Ethereum hardforks that are activated by timestamp rather than block number.
+const TIMESTAMP_FORKS: [Hardfork; 2] = [Hardfork::Shanghai, Hardfork::Cancun];
+
+/// Returns a post-merge [ChainSpec] where the given timestamp based hardforks activate at the
+/// given offsets (in seconds) from the genesis timestamp.
+///
+/// All hardforks up to and including Paris are activated at genesis.
+///
+/// # Panics
+///
+/// If any of the given hardforks is not an Ethereum timestamp based hardfork, i.e. not Shanghai
+/// or Cancun.
+pub fn fork_at_offsets(offsets: BTreeMap<Hardfork, u64>) -> ChainSpec {
+    let genesis_timestamp = MAINNET.genesis.timestamp;
+    offsets
+        .into_iter()
+        .fold(builder().paris_activated(), |builder, (fork, offset)| {
+            assert!(TIMESTAMP_FORKS.contains(&fork), "{fork} is not activated by timestamp");
+            builder.with_fork(fork, ForkCondition::Timestamp(genesis_timestamp + offset))
+        })
+        .build()
+}
+
+/// Returns a preset for every post-merge hardfork, keyed by the latest active hardfork.
+///
+/// This can be used to run the same test under each fork.
+pub fn post_merge_presets() -> Vec<(Hardfork, ChainSpec)> {
+    [Hardfork::Paris, Hardfork::Shanghai, Hardfork::Cancun]
+        .into_iter()
+        .map(|fork| (fork, up_to(fork)))
+        .collect()
+}
+
+fn builder() -> ChainSpecBuilder {
+    ChainSpecBuilder::default().chain(MAINNET.chain).genesis(MAINNET.genesis.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_forks_active_at_genesis() {
+        let spec = all_forks_active();
+        assert!(spec.fork(Hardfork::Frontier).active_at_block(0));
+        assert!(spec.is_shanghai_active_at_timestamp(0));
+        assert!(spec.is_cancun_active_at_timestamp(0));
+    }
+
+    #[test]
+    fn up_to_stops_at_fork() {
+        let spec = up_to(Hardfork::Shanghai);
+        assert!(spec.is_shanghai_active_at_timestamp(0));
+        assert!(!spec.is_cancun_active_at_timestamp(u64::MAX));
+    }
+
+    #[test]
+    fn up_to_maps_forks_without_helper_to_preceding_preset() {
+        assert_eq!(up_to(Hardfork::Dao).hardforks, up_to(Hardfork::Homestead).hardforks);
+        assert_eq!(up_to(Hardfork::MuirGlacier).hardforks, up_to(Hardfork::Istanbul).hardforks);
+        assert_eq!(up_to(Hardfork::GrayGlacier).hardforks, up_to(Hardfork::London).hardforks);
+    }
+
+    #[test]
+    fn forks_at_offsets() {
+        let spec =
+            fork_at_offsets(BTreeMap::from([(Hardfork::Shanghai, 10), (Hardfork::Cancun, 20)]));
+        assert!(spec.fork(Hardfork::Paris).active_at_block(0));
+        assert!(!spec.is_shanghai_active_at_timestamp(9));
+        assert!(spec.is_shanghai_active_at_timestamp(10));
+        assert!(!spec.is_cancun_active_at_timestamp(19));
+        assert!(spec.is_cancun_active_at_timestamp(20));
+    }
+
+    #[test]
+    #[should_panic]
+    fn forks_at_offsets_rejects_block_forks() {
+        fork_at_offsets(BTreeMap::from([(Hardfork::London, 1)]));
+    }
+}
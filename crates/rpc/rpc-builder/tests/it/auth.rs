@@ -1,15 +1,43 @@
 //! Auth server tests
 
 use crate::utils::launch_auth;
-use jsonrpsee::core::client::{ClientT, SubscriptionClientT};
+use hyper::header::AUTHORIZATION;
+use jsonrpsee::{
+    core::{
+        client::{ClientT, SubscriptionClientT},
+        error::Error,
+    },
+    http_client::{HeaderMap, HttpClient, HttpClientBuilder},
+    ws_client::WsClientBuilder,
+};
 use reth_node_ethereum::EthEngineTypes;
 use reth_primitives::{Block, U64};
-use reth_rpc::JwtSecret;
+use reth_rpc::{Claims, JwtSecret};
 use reth_rpc_api::clients::EngineApiClient;
 use reth_rpc_types::engine::{ForkchoiceState, PayloadId, TransitionConfiguration};
 use reth_rpc_types_compat::engine::payload::{
     convert_block_to_payload_input_v2, try_block_to_payload_v1,
 };
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Returns the authorization headers for a token signed with `secret` and issued at `iat`.
+fn auth_headers(secret: &JwtSecret, iat: SystemTime) -> HeaderMap {
+    let claims = Claims { iat: iat.duration_since(UNIX_EPOCH).unwrap().as_secs(), exp: None };
+    let bearer = format!("Bearer {}", secret.encode(&claims).unwrap());
+    HeaderMap::from_iter([(AUTHORIZATION, bearer.parse().unwrap())])
+}
+
+fn http_client_with_headers(url: String, headers: HeaderMap) -> HttpClient {
+    HttpClientBuilder::default().set_headers(headers).build(url).unwrap()
+}
+
+/// Asserts that the request was rejected by the auth layer before reaching the engine API.
+async fn assert_unauthorized(client: &HttpClient) {
+    let err =
+        EngineApiClient::<EthEngineTypes>::exchange_capabilities(client, vec![]).await.unwrap_err();
+    assert!(matches!(err, Error::Transport(_)), "expected transport error, got {err:?}");
+    assert!(err.to_string().contains("401"), "expected 401, got {err}");
+}
 #[allow(unused_must_use)]
 async fn test_basic_engine_calls<C>(client: &C)
 where
@@ -46,3 +74,47 @@ async fn test_auth_endpoints_ws() {
     let client = handle.ws_client().await;
     test_basic_engine_calls(&client).await
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auth_rejects_invalid_tokens_http() {
+    reth_tracing::init_test_tracing();
+    let secret = JwtSecret::random();
+    let handle = launch_auth(secret.clone()).await;
+
+    // sanity check: a fresh token signed with the server's secret is accepted
+    let client =
+        http_client_with_headers(handle.http_url(), auth_headers(&secret, SystemTime::now()));
+    EngineApiClient::<EthEngineTypes>::exchange_capabilities(&client, vec![]).await.unwrap();
+
+    // token signed with a different secret, e.g. after the server's secret was rotated
+    let client = http_client_with_headers(
+        handle.http_url(),
+        auth_headers(&JwtSecret::random(), SystemTime::now()),
+    );
+    assert_unauthorized(&client).await;
+
+    // token issued too long ago
+    let client = http_client_with_headers(
+        handle.http_url(),
+        auth_headers(&secret, SystemTime::now() - Duration::from_secs(120)),
+    );
+    assert_unauthorized(&client).await;
+
+    // no token at all
+    let client = http_client_with_headers(handle.http_url(), HeaderMap::new());
+    assert_unauthorized(&client).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auth_rejects_invalid_tokens_ws() {
+    reth_tracing::init_test_tracing();
+    let secret = JwtSecret::random();
+    let handle = launch_auth(secret).await;
+
+    // the ws handshake is rejected when the token is signed with a different secret
+    let res = WsClientBuilder::default()
+        .set_headers(auth_headers(&JwtSecret::random(), SystemTime::now()))
+        .build(handle.ws_url())
+        .await;
+    assert!(res.is_err());
+}
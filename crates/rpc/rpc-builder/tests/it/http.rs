@@ -1,6 +1,8 @@
 #![allow(unreachable_pub)]
 //! Standalone http tests
 
+#[cfg(unix)]
+use crate::utils::launch_http_ws_ipc_with_modules;
 use crate::utils::{launch_http, launch_http_ws, launch_ws};
 use jsonrpsee::{
     core::{
@@ -10,6 +12,8 @@ use jsonrpsee::{
     },
     types::error::ErrorCode,
 };
+#[cfg(unix)]
+use reth_ipc::client::IpcClientBuilder;
 use reth_primitives::{
     hex_literal::hex, Address, BlockId, BlockNumberOrTag, Bytes, NodeRecord, TxHash, B256, B64,
     U256,
@@ -38,6 +42,13 @@ fn is_unimplemented(err: Error) -> bool {
     }
 }
 
+fn is_method_not_found(err: Error) -> bool {
+    match err {
+        Error::Call(error_obj) => error_obj.code() == ErrorCode::MethodNotFound.code(),
+        _ => false,
+    }
+}
+
 /// Represents a builder for creating JSON-RPC requests.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct RawRpcParamsBuilder {
@@ -525,6 +536,37 @@ async fn test_eth_logs_args() {
     let _resp = client.request::<Vec<Log>, _>("eth_getLogs", params).await.unwrap();
 }
 
+// the ipc client is only available on unix
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_disabled_module_functions_per_transport() {
+    reth_tracing::init_test_tracing();
+
+    // eth is only exposed over http, admin only over ws and web3 only over ipc
+    let handle = launch_http_ws_ipc_with_modules(
+        vec![RethRpcModule::Eth],
+        vec![RethRpcModule::Admin],
+        vec![RethRpcModule::Web3],
+    )
+    .await;
+    let http_client = handle.http_client().unwrap();
+    let ws_client = handle.ws_client().await.unwrap();
+    let ipc_client =
+        IpcClientBuilder::default().build(handle.ipc_endpoint().unwrap()).await.unwrap();
+
+    EthApiClient::chain_id(&http_client).await.unwrap();
+    assert!(is_method_not_found(AdminApiClient::node_info(&http_client).await.err().unwrap()));
+    assert!(is_method_not_found(Web3ApiClient::client_version(&http_client).await.err().unwrap()));
+
+    AdminApiClient::node_info(&ws_client).await.unwrap();
+    assert!(is_method_not_found(EthApiClient::chain_id(&ws_client).await.err().unwrap()));
+    assert!(is_method_not_found(Web3ApiClient::client_version(&ws_client).await.err().unwrap()));
+
+    Web3ApiClient::client_version(&ipc_client).await.unwrap();
+    assert!(is_method_not_found(EthApiClient::chain_id(&ipc_client).await.err().unwrap()));
+    assert!(is_method_not_found(AdminApiClient::node_info(&ipc_client).await.err().unwrap()));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 mod auth;
 mod http;
+mod pubsub;
 mod serde;
 mod startup;
 pub mod utils;
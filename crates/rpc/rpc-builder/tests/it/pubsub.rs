@@ -0,0 +1,190 @@
+//! Standalone ws subscription tests
+
+use crate::utils::{launch_ws, launch_ws_with_builder, test_rpc_builder};
+use jsonrpsee::{
+    core::client::{Subscription, SubscriptionClientT},
+    rpc_params,
+};
+use reth_interfaces::test_utils::generators::{self, random_block, random_log, random_receipt};
+use reth_primitives::{Receipts, SealedBlockWithSenders, TxHash};
+use reth_provider::{test_utils::TestCanonStateSubscriptions, BundleStateWithReceipts, Chain};
+use reth_rpc_builder::RethRpcModule;
+use reth_rpc_types::{Header, Log};
+use reth_transaction_pool::{
+    test_utils::{testing_pool, MockTransaction},
+    PoolTransaction, TransactionPool,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+
+/// How long to wait for a subscription notification before failing the test.
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Awaits the next notification of the subscription.
+///
+/// # Panics
+///
+/// If the subscription is closed or no notification arrives within [NOTIFICATION_TIMEOUT].
+async fn next_notification<T: DeserializeOwned>(sub: &mut Subscription<T>) -> T {
+    tokio::time::timeout(NOTIFICATION_TIMEOUT, sub.next())
+        .await
+        .expect("timed out waiting for notification")
+        .expect("subscription closed")
+        .unwrap()
+}
+
+/// Returns a single block chain whose only transaction emitted one log.
+fn chain_with_log() -> (SealedBlockWithSenders, Arc<Chain>) {
+    let mut rng = generators::rng();
+    let block = random_block(&mut rng, 1, None, Some(1), Some(0)).seal_with_senders().unwrap();
+
+    let mut receipt = random_receipt(&mut rng, &block.body[0], Some(0));
+    receipt.success = true;
+    receipt.logs = vec![random_log(&mut rng, None, Some(1))];
+    let state = BundleStateWithReceipts::new(
+        Default::default(),
+        Receipts::from_vec(vec![vec![Some(receipt)]]),
+        1,
+    );
+
+    let chain = Arc::new(Chain::from_block(block.clone(), state, None));
+    (block, chain)
+}
+
+/// Repeatedly commits the given chain until the returned task is aborted.
+///
+/// Subscriptions only start listening for canonical state notifications on a task spawned after
+/// the subscription was accepted, so a single commit could be sent before anyone listens.
+fn commit_until_aborted(
+    mut events: TestCanonStateSubscriptions,
+    chain: Arc<Chain>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            events.add_next_commit(chain.clone());
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_new_heads_ws() {
+    reth_tracing::init_test_tracing();
+
+    let events = TestCanonStateSubscriptions::default();
+    let handle = launch_ws_with_builder(
+        vec![RethRpcModule::Eth],
+        test_rpc_builder().with_events(events.clone()),
+    )
+    .await;
+    let client = handle.ws_client().await.unwrap();
+
+    let mut sub: Subscription<Header> = client
+        .subscribe("eth_subscribe", rpc_params!["newHeads"], "eth_unsubscribe")
+        .await
+        .unwrap();
+
+    let (block, chain) = chain_with_log();
+    let committer = commit_until_aborted(events, chain);
+
+    let header = next_notification(&mut sub).await;
+    committer.abort();
+    assert_eq!(header.hash, Some(block.hash()));
+
+    sub.unsubscribe().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_logs_ws() {
+    reth_tracing::init_test_tracing();
+
+    let events = TestCanonStateSubscriptions::default();
+    let handle = launch_ws_with_builder(
+        vec![RethRpcModule::Eth],
+        test_rpc_builder().with_events(events.clone()),
+    )
+    .await;
+    let client = handle.ws_client().await.unwrap();
+
+    let mut sub: Subscription<Log> =
+        client.subscribe("eth_subscribe", rpc_params!["logs"], "eth_unsubscribe").await.unwrap();
+
+    let (block, chain) = chain_with_log();
+    let expected = chain.state().receipts_by_block(1)[0].clone().unwrap().logs[0].clone();
+    let committer = commit_until_aborted(events, chain);
+
+    let log = next_notification(&mut sub).await;
+    committer.abort();
+    assert_eq!(log.address, expected.address);
+    assert_eq!(log.topics, expected.topics);
+    assert_eq!(log.block_hash, Some(block.hash()));
+    assert_eq!(log.transaction_hash, Some(block.body[0].hash()));
+    assert!(!log.removed);
+
+    sub.unsubscribe().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_new_pending_transactions_ws() {
+    reth_tracing::init_test_tracing();
+
+    let pool = testing_pool();
+    let handle = launch_ws_with_builder(
+        vec![RethRpcModule::Eth],
+        test_rpc_builder().with_pool(pool.clone()),
+    )
+    .await;
+    let client = handle.ws_client().await.unwrap();
+
+    let mut sub: Subscription<TxHash> = client
+        .subscribe("eth_subscribe", rpc_params!["newPendingTransactions"], "eth_unsubscribe")
+        .await
+        .unwrap();
+
+    // the pool listener is registered on a task spawned after the subscription was accepted, so
+    // keep adding transactions until notifications arrive
+    let added = Arc::new(Mutex::new(Vec::new()));
+    let adder = {
+        let (pool, added) = (pool.clone(), added.clone());
+        tokio::spawn(async move {
+            loop {
+                let tx = MockTransaction::eip1559();
+                added.lock().unwrap().push(*tx.hash());
+                pool.add_external_transaction(tx).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+    };
+
+    let first = next_notification(&mut sub).await;
+    let second = next_notification(&mut sub).await;
+    adder.abort();
+
+    // once the listener is registered, transactions are notified in the order they were added
+    let added = added.lock().unwrap();
+    let idx = added.iter().position(|hash| *hash == first).expect("unknown transaction");
+    assert_eq!(added.get(idx + 1), Some(&second));
+
+    sub.unsubscribe().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_unknown_kind_ws() {
+    reth_tracing::init_test_tracing();
+
+    let handle = launch_ws(vec![RethRpcModule::Eth]).await;
+    let client = handle.ws_client().await.unwrap();
+
+    let res = client
+        .subscribe::<serde_json::Value, _>(
+            "eth_subscribe",
+            rpc_params!["unknownKind"],
+            "eth_unsubscribe",
+        )
+        .await;
+    assert!(res.is_err());
+}
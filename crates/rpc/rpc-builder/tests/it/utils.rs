@@ -13,7 +13,10 @@ use reth_rpc_builder::{
 use reth_rpc_engine_api::EngineApi;
 use reth_tasks::TokioTaskExecutor;
 use reth_transaction_pool::test_utils::{testing_pool, TestPool};
-use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::{
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use tokio::sync::mpsc::unbounded_channel;
 
 /// Localhost with port 0 so a free port is used.
@@ -21,6 +24,17 @@ pub fn test_address() -> SocketAddr {
     SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0))
 }
 
+/// Returns a unique ipc endpoint path in the temp dir, so tests can run in parallel.
+#[cfg(unix)]
+pub fn test_ipc_endpoint() -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir()
+        .join(format!("reth-test-{}-{id}.ipc", std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
 /// Launches a new server for the auth module
 pub async fn launch_auth(secret: JwtSecret) -> AuthServerHandle {
     let config = AuthServerConfig::builder(secret).socket_addr(test_address()).build();
@@ -49,7 +63,15 @@ pub async fn launch_http(modules: impl Into<RpcModuleSelection>) -> RpcServerHan
 
 /// Launches a new server with ws only with the given modules
 pub async fn launch_ws(modules: impl Into<RpcModuleSelection>) -> RpcServerHandle {
-    let builder = test_rpc_builder();
+    launch_ws_with_builder(modules, test_rpc_builder()).await
+}
+
+/// Launches a new server with ws only with the given modules, using the components of the given
+/// builder
+pub async fn launch_ws_with_builder(
+    modules: impl Into<RpcModuleSelection>,
+    builder: TestRpcModuleBuilder,
+) -> RpcServerHandle {
     let server = builder.build(TransportRpcModuleConfig::set_ws(modules));
     server
         .start_server(RpcServerConfig::ws(Default::default()).with_ws_address(test_address()))
@@ -74,6 +96,30 @@ pub async fn launch_http_ws(modules: impl Into<RpcModuleSelection>) -> RpcServer
         .unwrap()
 }
 
+/// Launches a new server with http, ws and ipc, each with its own set of modules
+#[cfg(unix)]
+pub async fn launch_http_ws_ipc_with_modules(
+    http_modules: impl Into<RpcModuleSelection>,
+    ws_modules: impl Into<RpcModuleSelection>,
+    ipc_modules: impl Into<RpcModuleSelection>,
+) -> RpcServerHandle {
+    let builder = test_rpc_builder();
+    let server = builder.build(
+        TransportRpcModuleConfig::set_ws(ws_modules).with_http(http_modules).with_ipc(ipc_modules),
+    );
+    server
+        .start_server(
+            RpcServerConfig::ws(Default::default())
+                .with_ws_address(test_address())
+                .with_http(Default::default())
+                .with_http_address(test_address())
+                .with_ipc(Default::default())
+                .with_ipc_endpoint(test_ipc_endpoint()),
+        )
+        .await
+        .unwrap()
+}
+
 /// Launches a new server with http and ws and with the given modules on the same port.
 pub async fn launch_http_ws_same_port(modules: impl Into<RpcModuleSelection>) -> RpcServerHandle {
     let builder = test_rpc_builder();
@@ -92,15 +138,18 @@ pub async fn launch_http_ws_same_port(modules: impl Into<RpcModuleSelection>) ->
         .unwrap()
 }
 
-/// Returns an [RpcModuleBuilder] with testing components.
-pub fn test_rpc_builder() -> RpcModuleBuilder<
+/// The [RpcModuleBuilder] with testing components returned by [test_rpc_builder].
+pub type TestRpcModuleBuilder = RpcModuleBuilder<
     NoopProvider,
     TestPool,
     NoopNetwork,
     TokioTaskExecutor,
     TestCanonStateSubscriptions,
     EthEvmConfig,
-> {
+>;
+
+/// Returns an [RpcModuleBuilder] with testing components.
+pub fn test_rpc_builder() -> TestRpcModuleBuilder {
     RpcModuleBuilder::default()
         .with_provider(NoopProvider::default())
         .with_pool(testing_pool())
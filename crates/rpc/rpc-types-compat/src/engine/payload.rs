@@ -392,14 +392,38 @@ pub fn execution_payload_from_sealed_block(value: SealedBlock) -> ExecutionPaylo
 #[cfg(test)]
 mod tests {
     use super::{
-        block_to_payload_v3, try_into_block, try_payload_v3_to_block, validate_block_hash,
+        block_to_payload_v3, try_into_block, try_payload_v1_to_block, try_payload_v3_to_block,
+        validate_block_hash,
+    };
+    use reth_primitives::{
+        b256,
+        constants::{MAXIMUM_EXTRA_DATA_SIZE, MIN_PROTOCOL_BASE_FEE},
+        hex, Bytes, U256,
     };
-    use reth_primitives::{b256, hex, Bytes, U256};
     use reth_rpc_types::{
-        engine::{CancunPayloadFields, ExecutionPayloadV3},
+        engine::{CancunPayloadFields, ExecutionPayloadV3, PayloadError},
         ExecutionPayload, ExecutionPayloadV1, ExecutionPayloadV2,
     };
 
+    fn empty_payload_v1() -> ExecutionPayloadV1 {
+        ExecutionPayloadV1 {
+            parent_hash: Default::default(),
+            fee_recipient: Default::default(),
+            state_root: Default::default(),
+            receipts_root: Default::default(),
+            logs_bloom: Default::default(),
+            prev_randao: Default::default(),
+            block_number: 1,
+            gas_limit: 30_000_000,
+            gas_used: 0,
+            timestamp: 0,
+            extra_data: Default::default(),
+            base_fee_per_gas: U256::from(MIN_PROTOCOL_BASE_FEE),
+            block_hash: Default::default(),
+            transactions: vec![],
+        }
+    }
+
     #[test]
     fn roundtrip_payload_to_block() {
         let first_transaction_raw = Bytes::from_static(&hex!("02f9017a8501a1f0ff438211cc85012a05f2008512a05f2000830249f094d5409474fd5a725eab2ac9a8b26ca6fb51af37ef80b901040cc7326300000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000001bdd2ed4b616c800000000000000000000000000001e9ee781dd4b97bdef92e5d1785f73a1f931daa20000000000000000000000007a40026a3b9a41754a95eec8c92c6b99886f440c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000009ae80eb647dd09968488fa1d7e412bf8558a0b7a0000000000000000000000000f9815537d361cb02befd9918c95c97d4d8a4a2bc001a0ba8f1928bb0efc3fcd01524a2039a9a2588fa567cd9a7cc18217e05c615e9d69a0544bfd11425ac7748e76b3795b57a5563e2b0eff47b5428744c62ff19ccfc305")[..]);
@@ -620,4 +644,29 @@ mod tests {
         // Ensure the actual hash is calculated if we set the fields to what they should be
         validate_block_hash(block_hash_with_blob_fee_fields, block).unwrap();
     }
+
+    #[test]
+    fn payload_to_block_rejects_oversized_extra_data() {
+        let mut payload = empty_payload_v1();
+        payload.extra_data = Bytes::from(vec![0u8; MAXIMUM_EXTRA_DATA_SIZE]);
+        try_payload_v1_to_block(payload.clone()).unwrap();
+
+        let extra_data = Bytes::from(vec![0u8; MAXIMUM_EXTRA_DATA_SIZE + 1]);
+        payload.extra_data = extra_data.clone();
+        assert!(matches!(
+            try_payload_v1_to_block(payload).unwrap_err(),
+            PayloadError::ExtraData(data) if data == extra_data
+        ));
+    }
+
+    #[test]
+    fn payload_to_block_rejects_base_fee_below_minimum() {
+        let mut payload = empty_payload_v1();
+        let base_fee = U256::from(MIN_PROTOCOL_BASE_FEE - 1);
+        payload.base_fee_per_gas = base_fee;
+        assert!(matches!(
+            try_payload_v1_to_block(payload).unwrap_err(),
+            PayloadError::BaseFee(fee) if fee == base_fee
+        ));
+    }
 }
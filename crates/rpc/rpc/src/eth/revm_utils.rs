@@ -604,6 +604,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reth_primitives::{Bytes, U64};
+    use reth_provider::test_utils::NoopProvider;
+    use reth_revm::database::StateProviderDatabase;
 
     #[test]
     fn test_ensure_0_fallback() {
@@ -634,4 +637,68 @@ mod tests {
         assert!(gas_price.is_zero());
         assert_eq!(max_fee_per_blob_gas, Some(U256::from(99)));
     }
+
+    #[test]
+    fn test_apply_block_overrides() {
+        let mut env = BlockEnv::default();
+        let coinbase = Address::with_last_byte(1);
+        let overrides = BlockOverrides {
+            number: Some(U256::from(10)),
+            time: Some(U64::from(1_700_000_000)),
+            coinbase: Some(coinbase),
+            base_fee: Some(U256::from(7)),
+            ..Default::default()
+        };
+        apply_block_overrides(overrides, &mut env);
+
+        assert_eq!(env.number, U256::from(10));
+        assert_eq!(env.timestamp, U256::from(1_700_000_000));
+        assert_eq!(env.coinbase, coinbase);
+        assert_eq!(env.basefee, U256::from(7));
+    }
+
+    #[test]
+    fn test_apply_state_overrides() {
+        let mut db = CacheDB::new(StateProviderDatabase::new(NoopProvider::default()));
+        let address = Address::with_last_byte(1);
+        let slot = B256::with_last_byte(2);
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let overrides = StateOverride::from([(
+            address,
+            AccountOverride {
+                balance: Some(U256::from(100)),
+                code: Some(code.clone()),
+                state_diff: Some([(slot, U256::from(3))].into_iter().collect()),
+                ..Default::default()
+            },
+        )]);
+        apply_state_overrides(overrides, &mut db).unwrap();
+
+        let account = db.basic_ref(address).unwrap().unwrap();
+        assert_eq!(account.balance, U256::from(100));
+        assert_eq!(account.code, Some(Bytecode::new_raw(code)));
+        assert_eq!(db.storage_ref(address, U256::from_be_bytes(slot.0)).unwrap(), U256::from(3));
+
+        // the overrides are only applied to the cache and not to the underlying state
+        assert_eq!(db.db.basic_ref(address).unwrap(), None);
+    }
+
+    #[test]
+    fn test_apply_state_overrides_both_state_and_state_diff() {
+        let mut db = CacheDB::new(StateProviderDatabase::new(NoopProvider::default()));
+        let address = Address::with_last_byte(1);
+        let overrides = StateOverride::from([(
+            address,
+            AccountOverride {
+                state: Some(Default::default()),
+                state_diff: Some(Default::default()),
+                ..Default::default()
+            },
+        )]);
+
+        let err = apply_state_overrides(overrides, &mut db).unwrap_err();
+        assert!(
+            matches!(err, EthApiError::BothStateAndStateDiffInOverride(addr) if addr == address)
+        );
+    }
 }
@@ -2840,4 +2840,43 @@ mod tests {
             assert!(pool.size().blob <= blob_limit.max_txs);
         }
     }
+
+    #[test]
+    fn discard_pending_at_capacity_lowest_fee_first() {
+        let mut f = MockTransactionFactory::default();
+        let pending_limit = SubPoolLimit::new(10, usize::MAX);
+        let mut pool = TxPool::new(
+            MockOrdering::default(),
+            PoolConfig { pending_limit, ..Default::default() },
+        );
+
+        // overfill the pending pool with independent transactions of increasing fees
+        let mut txs = Vec::new();
+        for i in 0..pending_limit.max_txs * 2 {
+            let tx = MockTransaction::eip1559().inc_price_by(i as u128 + 1);
+            let validated = f.validated(tx.clone());
+            pool.add_transaction(validated, U256::from(1_000), 0).unwrap();
+            txs.push(tx);
+        }
+        assert_eq!(pool.size().pending, pending_limit.max_txs * 2);
+
+        let removed = pool.discard_worst();
+        pool.assert_invariants();
+        assert_eq!(removed.len(), pending_limit.max_txs);
+        assert_eq!(pool.size().pending, pending_limit.max_txs);
+        assert_eq!(pool.size().total, pending_limit.max_txs);
+
+        // the lowest fee transactions were evicted
+        let (evicted, kept) = txs.split_at(pending_limit.max_txs);
+        for tx in evicted {
+            assert!(removed.iter().any(|removed| removed.hash() == tx.hash()));
+        }
+
+        // and all of the highest fee transactions are still available for block building
+        let best = pool.best_transactions().map(|tx| *tx.hash()).collect::<Vec<_>>();
+        assert_eq!(best.len(), kept.len());
+        for (best, tx) in best.iter().zip(kept.iter().rev()) {
+            assert_eq!(best, tx.hash());
+        }
+    }
 }
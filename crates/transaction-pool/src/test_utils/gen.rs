@@ -2,8 +2,8 @@ use crate::EthPooledTransaction;
 use rand::Rng;
 use reth_primitives::{
     constants::MIN_PROTOCOL_BASE_FEE, sign_message, AccessList, Address, Bytes,
-    FromRecoveredTransaction, Transaction, TransactionKind, TransactionSigned, TxEip1559, TxLegacy,
-    TxValue, B256, MAINNET,
+    FromRecoveredTransaction, Transaction, TransactionKind, TransactionSigned, TxEip1559,
+    TxEip2930, TxLegacy, TxValue, B256, MAINNET,
 };
 
 /// A generator for transactions for testing purposes.
@@ -86,6 +86,16 @@ impl<R: Rng> TransactionGenerator<R> {
             .gas_limit(self.gas_limit)
     }
 
+    /// Creates a new legacy transaction with a random signer
+    pub fn gen_legacy(&mut self) -> TransactionSigned {
+        self.transaction().into_legacy()
+    }
+
+    /// Creates a new EIP-2930 transaction with a random signer and the given access list
+    pub fn gen_eip2930(&mut self, access_list: AccessList) -> TransactionSigned {
+        self.transaction().access_list(access_list).into_eip2930()
+    }
+
     /// Creates a new transaction with a random signer
     pub fn gen_eip1559(&mut self) -> TransactionSigned {
         self.transaction().into_eip1559()
@@ -144,6 +154,24 @@ impl TransactionBuilder {
         )
     }
 
+    /// Converts the transaction builder into a transaction format using EIP-2930.
+    pub fn into_eip2930(self) -> TransactionSigned {
+        TransactionBuilder::signed(
+            TxEip2930 {
+                chain_id: self.chain_id,
+                nonce: self.nonce,
+                gas_limit: self.gas_limit,
+                gas_price: self.max_fee_per_gas,
+                to: self.to,
+                value: self.value,
+                access_list: self.access_list,
+                input: self.input,
+            }
+            .into(),
+            self.signer,
+        )
+    }
+
     /// Converts the transaction builder into a transaction format using EIP-1559.
     pub fn into_eip1559(self) -> TransactionSigned {
         TransactionBuilder::signed(
@@ -323,6 +351,7 @@ impl Default for TransactionBuilder {
 mod tests {
     use super::*;
     use rand::thread_rng;
+    use reth_primitives::AccessListItem;
 
     #[test]
     fn test_generate_transaction() {
@@ -331,4 +360,19 @@ mod tests {
         let _tx = gen.transaction().into_legacy();
         let _tx = gen.transaction().into_eip1559();
     }
+
+    #[test]
+    fn test_generate_envelope_types() {
+        let mut gen = TransactionGenerator::new(thread_rng());
+        assert!(gen.gen_legacy().is_legacy());
+
+        let access_list = AccessList(vec![AccessListItem {
+            address: Address::random(),
+            storage_keys: vec![B256::random()],
+        }]);
+        let tx = gen.gen_eip2930(access_list.clone());
+        assert!(tx.is_eip2930());
+        assert_eq!(tx.access_list(), Some(&access_list));
+        assert!(tx.recover_signer().is_some());
+    }
 }
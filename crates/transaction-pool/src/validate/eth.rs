@@ -571,6 +571,17 @@ impl EthTransactionValidatorBuilder {
         self
     }
 
+    /// Disables the eip4844 support.
+    pub const fn no_eip4844(self) -> Self {
+        self.set_eip4844(false)
+    }
+
+    /// Set the eip4844 support.
+    pub const fn set_eip4844(mut self, eip4844: bool) -> Self {
+        self.eip4844 = eip4844;
+        self
+    }
+
     /// Sets the [KzgSettings] to use for validating KZG proofs.
     pub fn kzg_settings(mut self, kzg_settings: Arc<KzgSettings>) -> Self {
         self.kzg_settings = kzg_settings;
@@ -798,6 +809,81 @@ mod tests {
         assert!(tx.is_some());
     }
 
+    #[cfg(not(feature = "optimism"))]
+    #[test]
+    fn validate_blob_transaction_fork_gating() {
+        use super::*;
+        use crate::{
+            blobstore::{BlobStore, InMemoryBlobStore},
+            EthPooledTransaction,
+        };
+        use reth_primitives::{
+            sign_message,
+            test_chain_specs::{all_forks_active, post_merge_presets},
+            BlobTransactionSidecar, Hardfork, Transaction, TransactionKind, TransactionSigned,
+            TxEip4844, B256, U256,
+        };
+        use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+        let transaction = Transaction::Eip4844(TxEip4844 {
+            chain_id: 1,
+            gas_limit: 21_000,
+            max_fee_per_gas: 1,
+            to: TransactionKind::Call(Default::default()),
+            blob_versioned_hashes: vec![B256::with_last_byte(1)],
+            max_fee_per_blob_gas: 1,
+            ..Default::default()
+        });
+        let signature =
+            sign_message(B256::with_last_byte(1), transaction.signature_hash()).unwrap();
+        let signed = TransactionSigned::from_transaction_and_signature(transaction, signature);
+        let recovered = signed.into_ecrecovered().unwrap();
+        let len = recovered.length_without_header();
+        let transaction = EthPooledTransaction::new(recovered, len);
+
+        let provider = MockEthProvider::default();
+        provider.add_account(transaction.sender(), ExtendedAccount::new(0, U256::MAX));
+        // the sidecar was validated when the transaction was first inserted
+        let blob_store = InMemoryBlobStore::default();
+        blob_store.insert(*transaction.hash(), BlobTransactionSidecar::default()).unwrap();
+
+        for (fork, chain_spec) in post_merge_presets() {
+            let validator = EthTransactionValidatorBuilder::new(Arc::new(chain_spec))
+                .build(provider.clone(), blob_store.clone());
+            let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+
+            if fork == Hardfork::Cancun {
+                assert!(outcome.is_valid(), "{fork}: {outcome:?}");
+            } else {
+                assert!(
+                    matches!(
+                        outcome,
+                        TransactionValidationOutcome::Invalid(
+                            _,
+                            InvalidPoolTransactionError::Consensus(
+                                InvalidTransactionError::TxTypeNotSupported
+                            )
+                        )
+                    ),
+                    "{fork}: {outcome:?}"
+                );
+            }
+        }
+
+        // blob transactions can also be disabled explicitly
+        let validator = EthTransactionValidatorBuilder::new(Arc::new(all_forks_active()))
+            .no_eip4844()
+            .build(provider, blob_store);
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::Consensus(InvalidTransactionError::Eip4844Disabled)
+            )
+        ));
+    }
+
     #[cfg(feature = "optimism")]
     #[tokio::test(flavor = "multi_thread")]
     async fn validate_optimism_transaction() {